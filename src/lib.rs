@@ -8,42 +8,218 @@
 //! in the buffer if the write half is dropped.
 
 use std::{rc::{Rc, Weak}, collections::VecDeque, cell::RefCell};
-use std::io::{Read, Write};
+use std::io::{BufRead, Read, Write};
+
+/// Backing state shared between the read and write halves.
+///
+/// The byte queue lives here alongside the channel-wide byte `limit` and an optional reader
+/// [`Waker`](std::task::Waker), parked by the async read path (see the `async` feature) while it
+/// waits for a writer to make bytes available. Keeping the limit here rather than on an individual
+/// [`WriteChannel`] means every cloned writer shares one consistent backpressure threshold.
+struct Shared {
+    queue: VecDeque<u8>,
+    /// Maximum number of bytes the queue is allowed to hold. Writes that would grow it past this
+    /// point get pushed back instead of allocating without bound.
+    limit: usize,
+    /// Reader parked on an empty queue, woken once a writer appends bytes (or the last writer drops).
+    #[cfg(feature = "async")]
+    reader_waker: Option<std::task::Waker>,
+    /// Writers parked on a full bounded queue, woken once the reader drains space.
+    #[cfg(feature = "async")]
+    writer_wakers: Vec<std::task::Waker>,
+}
+
+#[cfg(feature = "async")]
+impl Shared {
+    /// Wake the reader parked waiting for bytes, if any.
+    fn wake_reader(&mut self) {
+        if let Some(waker) = self.reader_waker.take() {
+            waker.wake();
+        }
+    }
+
+    /// Wake every writer parked on a full queue now that space has opened up.
+    fn wake_writers(&mut self) {
+        for waker in self.writer_wakers.drain(..) {
+            waker.wake();
+        }
+    }
+}
 
 /// Read half of the channel
 pub struct ReadChannel {
-    buffer: Rc<RefCell<VecDeque<u8>>>,
+    buffer: Rc<RefCell<Shared>>,
+    /// Bytes moved out of the shared queue so [`BufRead::fill_buf`] can lend a slice that no writer
+    /// can invalidate. This buffer is owned solely by the read half, so a borrow of it is tied to
+    /// `&mut self` and stays valid until the next read-side mutation. It is always drained before
+    /// the shared queue to preserve byte order.
+    staged: VecDeque<u8>,
+}
+
+impl ReadChannel {
+    /// Returns `true` once every write half has been dropped.
+    ///
+    /// Bytes already sitting in the buffer can still be read; a closed channel just means no new
+    /// bytes will ever arrive.
+    pub fn is_closed(&self) -> bool {
+        Rc::weak_count(&self.buffer) == 0
+    }
+
+    /// Move the entire buffered contents out of the channel in one shot.
+    ///
+    /// The backing `VecDeque` is swapped for an empty one and converted into a `Vec`, so no bytes
+    /// are copied out individually. The returned vector may be empty if nothing is currently
+    /// buffered.
+    pub fn drain_to_vec(&mut self) -> Vec<u8> {
+        let mut data = self.buffer.borrow_mut();
+        if self.staged.is_empty() {
+            let out = Vec::from(std::mem::take(&mut data.queue));
+            #[cfg(feature = "async")]
+            data.wake_writers();
+            return out;
+        }
+        // Anything already staged for `BufRead` comes first, then the rest of the shared queue.
+        let mut out = Vec::from(std::mem::take(&mut self.staged));
+        out.extend(data.queue.drain(..));
+        #[cfg(feature = "async")]
+        data.wake_writers();
+        out
+    }
 }
 
 impl Read for ReadChannel {
     fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        // Serve bytes previously staged by `fill_buf` before touching the shared queue so ordering
+        // is preserved across mixed `Read`/`BufRead` use.
+        if !self.staged.is_empty() {
+            let to_read = self.staged.len().min(buf.len());
+            let contiguous = self.staged.make_contiguous();
+            buf[..to_read].copy_from_slice(&contiguous[..to_read]);
+            self.staged.drain(..to_read);
+            return Ok(to_read);
+        }
         let mut data = self.buffer.borrow_mut();
-        let to_read = data.len().min(buf.len());
-        for i in 0..to_read {
-            buf[i] = data.pop_front().unwrap();
+        let to_read = data.queue.len().min(buf.len());
+        if data.queue.is_empty() {
+            // Nothing buffered: only report EOF once the writer is gone for good, otherwise the
+            // caller should come back later for more data.
+            if self.is_closed() {
+                return Ok(0);
+            }
+            return Err(std::io::Error::new(std::io::ErrorKind::WouldBlock, "No data buffered"));
         }
+        let contiguous = data.queue.make_contiguous();
+        buf[..to_read].copy_from_slice(&contiguous[..to_read]);
+        data.queue.drain(..to_read);
+        #[cfg(feature = "async")]
+        data.wake_writers();
         Ok(to_read)
     }
 }
 
+impl BufRead for ReadChannel {
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        if self.staged.is_empty() {
+            let mut data = self.buffer.borrow_mut();
+            if data.queue.is_empty() {
+                if self.is_closed() {
+                    // An empty slice signals EOF to `BufRead` consumers.
+                    return Ok(&[]);
+                }
+                return Err(std::io::Error::new(std::io::ErrorKind::WouldBlock, "No data buffered"));
+            }
+            // Move the queued bytes into our private buffer so the returned slice borrows state that
+            // only the read half owns; no writer can reallocate or mutate it from under the caller.
+            std::mem::swap(&mut self.staged, &mut data.queue);
+            #[cfg(feature = "async")]
+            data.wake_writers();
+        }
+        Ok(self.staged.make_contiguous())
+    }
+
+    fn consume(&mut self, amt: usize) {
+        // Saturate rather than panic on an over-large `amt`, matching `std::io::BufReader`.
+        let amt = amt.min(self.staged.len());
+        self.staged.drain(..amt);
+    }
+}
+
 /// Write half of the channel
+///
+/// `WriteChannel` is cloneable so a single reader can be fed by several independently-owned write
+/// handles, an mpsc-style topology. Every clone shares the same backing buffer through a `Weak`, so
+/// writes from any handle succeed while the reader lives and all fail with `BrokenPipe` once the
+/// reader is dropped. The buffer itself is only released when the [`ReadChannel`] is dropped.
+#[derive(Clone)]
 pub struct WriteChannel {
-    buffer: Weak<RefCell<VecDeque<u8>>>,
+    buffer: Weak<RefCell<Shared>>,
+}
+
+impl WriteChannel {
+    /// Returns `true` once the read half has been dropped.
+    ///
+    /// Any further writes on a closed channel fail with [`std::io::ErrorKind::BrokenPipe`].
+    pub fn is_closed(&self) -> bool {
+        self.buffer.upgrade().is_none()
+    }
+
+    /// Pump a reader straight into the shared buffer until it reaches end of stream.
+    ///
+    /// Bytes are copied from `r` directly onto the back of the queue in chunks, which is a good deal
+    /// cheaper than routing a generic byte source through the [`Write`] interface a slice at a time.
+    /// The channel's byte limit is honoured: each read is capped to the remaining capacity, so no
+    /// bytes are ever read past the limit, and once the buffer is full the pump stops and returns
+    /// [`std::io::ErrorKind::WouldBlock`] with `r` left positioned for a later resume. On an
+    /// unbounded channel this simply runs until `r` hits end of stream.
+    pub fn write_all_from<R: Read>(&mut self, r: &mut R) -> std::io::Result<()> {
+        let internal = self.buffer.upgrade().ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::BrokenPipe, "Reader no longer exists")
+        })?;
+        let mut data = internal.borrow_mut();
+        let mut chunk = [0u8; 8 * 1024];
+        loop {
+            let space = data.limit.saturating_sub(data.queue.len());
+            if space == 0 {
+                return Err(std::io::Error::new(std::io::ErrorKind::WouldBlock, "Channel is full"));
+            }
+            let cap = space.min(chunk.len());
+            let n = match r.read(&mut chunk[..cap]) {
+                Ok(0) => break,
+                Ok(n) => n,
+                // A transient interruption should not abort the transfer, matching `std::io::copy`.
+                Err(ref e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            data.queue.extend(&chunk[..n]);
+            // Wake an async reader parked on an empty queue now that bytes have arrived.
+            #[cfg(feature = "async")]
+            data.wake_reader();
+        }
+        Ok(())
+    }
 }
 
 impl Write for WriteChannel {
     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
         if let Some(internal) = self.buffer.upgrade() {
             let mut data = internal.borrow_mut();
-            data.extend(buf);
-            Ok(buf.len())
+            let space = data.limit.saturating_sub(data.queue.len());
+            if space == 0 {
+                return Err(std::io::Error::new(std::io::ErrorKind::WouldBlock, "Channel is full"));
+            }
+            let to_write = buf.len().min(space);
+            data.queue.extend(&buf[..to_write]);
+            // Wake a reader parked on the async read path now that bytes are available.
+            #[cfg(feature = "async")]
+            data.wake_reader();
+            Ok(to_write)
         } else {
             Err(std::io::Error::new(std::io::ErrorKind::BrokenPipe, "Reader no longer exists"))
         }
     }
 
     fn flush(&mut self) -> std::io::Result<()> {
-        if let Some(_) = self.buffer.upgrade() {
+        if self.buffer.upgrade().is_some() {
             Ok(())
         } else {
             Err(std::io::Error::new(std::io::ErrorKind::BrokenPipe, "Reader no longer exists"))
@@ -53,17 +229,148 @@ impl Write for WriteChannel {
 
 /// Create new read and write channels
 pub fn new_io_channel() -> (ReadChannel, WriteChannel) {
-    let buffer = Rc::new(RefCell::new(VecDeque::new()));
+    new_bounded_io_channel(usize::MAX)
+}
+
+/// Create new read and write channels whose buffer will not grow past `limit` bytes.
+///
+/// [`WriteChannel::write`] only accepts as many bytes as still fit under `limit`, and once the buffer
+/// holds `limit` bytes it returns [`std::io::ErrorKind::WouldBlock`] instead, giving producers that
+/// outpace the reader backpressure instead of unbounded memory growth. Draining the queue via the
+/// read half re-opens capacity for subsequent writes.
+pub fn new_bounded_io_channel(limit: usize) -> (ReadChannel, WriteChannel) {
+    let buffer = Rc::new(RefCell::new(Shared {
+        queue: VecDeque::new(),
+        limit,
+        #[cfg(feature = "async")]
+        reader_waker: None,
+        #[cfg(feature = "async")]
+        writer_wakers: Vec::new(),
+    }));
     let write = WriteChannel {
         buffer: Rc::downgrade(&buffer),
     };
     let read = ReadChannel {
         buffer,
+        staged: VecDeque::new(),
     };
     (read, write)
 }
 
+/// Single-threaded async adapters built on [`futures::AsyncRead`]/[`futures::AsyncWrite`].
+///
+/// These let the channel act as an in-memory byte pipe inside a `LocalSet`-style executor. The
+/// mechanism is a reader [`Waker`](std::task::Waker) stashed in [`Shared`]: a `poll_read` that finds
+/// the buffer empty while a writer is still alive parks the waker and returns `Poll::Pending`, and
+/// the next write (or the writer being dropped) wakes it. The `Rc`/`RefCell` design is preserved, so
+/// these futures are intentionally `!Send`.
+#[cfg(feature = "async")]
+mod r#async {
+    use super::*;
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    impl futures::AsyncRead for ReadChannel {
+        fn poll_read(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut [u8],
+        ) -> Poll<std::io::Result<usize>> {
+            let this = self.get_mut();
+            // Serve any bytes staged by `fill_buf` first to keep ordering consistent.
+            if !this.staged.is_empty() {
+                let to_read = this.staged.len().min(buf.len());
+                let contiguous = this.staged.make_contiguous();
+                buf[..to_read].copy_from_slice(&contiguous[..to_read]);
+                this.staged.drain(..to_read);
+                return Poll::Ready(Ok(to_read));
+            }
+            let mut data = this.buffer.borrow_mut();
+            let to_read = data.queue.len().min(buf.len());
+            if data.queue.is_empty() {
+                // A drained buffer is EOF only once every writer is gone; otherwise park the waker
+                // until a writer hands us more bytes (or drops).
+                if Rc::weak_count(&this.buffer) == 0 {
+                    return Poll::Ready(Ok(0));
+                }
+                data.reader_waker = Some(cx.waker().clone());
+                return Poll::Pending;
+            }
+            let contiguous = data.queue.make_contiguous();
+            buf[..to_read].copy_from_slice(&contiguous[..to_read]);
+            data.queue.drain(..to_read);
+            data.wake_writers();
+            Poll::Ready(Ok(to_read))
+        }
+    }
+
+    impl futures::AsyncWrite for WriteChannel {
+        fn poll_write(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<std::io::Result<usize>> {
+            let Some(internal) = self.buffer.upgrade() else {
+                return Poll::Ready(Err(std::io::Error::new(
+                    std::io::ErrorKind::BrokenPipe,
+                    "Reader no longer exists",
+                )));
+            };
+            let mut data = internal.borrow_mut();
+            let space = data.limit.saturating_sub(data.queue.len());
+            if space == 0 {
+                // The bounded buffer is full; park this writer and yield until the reader drains
+                // space and wakes us, rather than returning an unschedulable `Pending`.
+                if !data.writer_wakers.iter().any(|w| w.will_wake(cx.waker())) {
+                    data.writer_wakers.push(cx.waker().clone());
+                }
+                return Poll::Pending;
+            }
+            let to_write = buf.len().min(space);
+            data.queue.extend(&buf[..to_write]);
+            data.wake_reader();
+            Poll::Ready(Ok(to_write))
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+            if self.buffer.upgrade().is_some() {
+                Poll::Ready(Ok(()))
+            } else {
+                Poll::Ready(Err(std::io::Error::new(
+                    std::io::ErrorKind::BrokenPipe,
+                    "Reader no longer exists",
+                )))
+            }
+        }
+
+        fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+            self.poll_flush(cx)
+        }
+    }
+
+    impl Drop for WriteChannel {
+        fn drop(&mut self) {
+            // Wake a parked reader so it re-polls and observes EOF once this was the last writer,
+            // rather than hanging on a waker that will never fire again.
+            if let Some(internal) = self.buffer.upgrade() {
+                internal.borrow_mut().wake_reader();
+            }
+        }
+    }
+
+    impl Drop for ReadChannel {
+        fn drop(&mut self) {
+            // Wake any writers parked on a full buffer before the shared state is torn down, so they
+            // re-poll, fail to `upgrade()`, and resolve to `BrokenPipe` instead of hanging forever.
+            self.buffer.borrow_mut().wake_writers();
+        }
+    }
+}
+
 #[cfg(test)]
+// The suite uses the crate's long-standing `if let Ok(..) { .. } else { assert!(false) }` idiom for
+// asserting result shape; keep it rather than churn every case.
+#[allow(clippy::assertions_on_constants)]
 mod test {
     use super::*;
 
@@ -71,7 +378,7 @@ mod test {
     fn basic_read_write() {
         let mut buf: [u8; 5] = [0; 5];
         let (mut read, mut write) = new_io_channel();
-        write.write(&[1,2,3,4,5]).unwrap();
+        write.write_all(&[1,2,3,4,5]).unwrap();
         let res = read.read(&mut buf);
         if let Ok(s) = res {
             assert_eq!(s, 5);
@@ -93,6 +400,34 @@ mod test {
         }
     }
 
+    #[test]
+    fn bounded_backpressure() {
+        let (mut read, mut write) = new_bounded_io_channel(4);
+        // Only the first four bytes fit under the limit.
+        let res = write.write(&[1,2,3,4,5,6]);
+        if let Ok(s) = res {
+            assert_eq!(s, 4);
+        } else {
+            assert!(false);
+        }
+        // The buffer is full, so a further write gets pushed back.
+        let res = write.write(&[7,8]);
+        if let Err(e) = res {
+            assert_eq!(e.kind(), std::io::ErrorKind::WouldBlock);
+        } else {
+            assert!(false);
+        }
+        // Draining the reader re-opens capacity.
+        let mut buf: [u8; 4] = [0; 4];
+        assert_eq!(read.read(&mut buf).unwrap(), 4);
+        let res = write.write(&[7,8]);
+        if let Ok(s) = res {
+            assert_eq!(s, 2);
+        } else {
+            assert!(false);
+        }
+    }
+
     #[test]
     fn write_dropped() {
         let mut buf: [u8; 5] = [0; 5];
@@ -105,4 +440,227 @@ mod test {
             assert!(false);
         }
     }
+
+    #[test]
+    fn empty_but_alive_would_block() {
+        let mut buf: [u8; 5] = [0; 5];
+        let (mut read, write) = new_io_channel();
+        assert!(!read.is_closed());
+        assert!(!write.is_closed());
+        // Writer is still around, so an empty buffer is "no data yet", not EOF.
+        let res = read.read(&mut buf);
+        if let Err(e) = res {
+            assert_eq!(e.kind(), std::io::ErrorKind::WouldBlock);
+        } else {
+            assert!(false);
+        }
+        drop(write);
+        // Now the writer is gone and the buffer is drained, so it is a true EOF.
+        assert!(read.is_closed());
+        assert_eq!(read.read(&mut buf).unwrap(), 0);
+    }
+
+    #[test]
+    fn bufread_lines() {
+        use std::io::BufRead;
+        let (mut read, mut write) = new_io_channel();
+        write.write_all(b"first\nsecond\n").unwrap();
+        drop(write);
+        let mut lines = Vec::new();
+        for line in read.by_ref().lines() {
+            lines.push(line.unwrap());
+        }
+        assert_eq!(lines, vec!["first".to_string(), "second".to_string()]);
+    }
+
+    #[test]
+    fn fill_buf_slice_survives_concurrent_write() {
+        use std::io::BufRead;
+        let (mut read, mut write) = new_io_channel();
+        write.write_all(&[b'a'; 4]).unwrap();
+        let staged = read.fill_buf().unwrap();
+        // A large write through a separate handle must not invalidate the slice fill_buf handed out,
+        // because staged bytes live in a buffer owned solely by the read half.
+        write.write_all(&vec![0u8; 1_000_000]).unwrap();
+        assert_eq!(staged, &[b'a'; 4]);
+        read.consume(4);
+        // The earlier staged bytes are gone; the bulk write is still readable in order.
+        let mut next = [0u8; 4];
+        assert_eq!(read.read(&mut next).unwrap(), 4);
+        assert_eq!(next, [0u8; 4]);
+    }
+
+    #[test]
+    fn bulk_read_into_small_buffer() {
+        let (mut read, mut write) = new_io_channel();
+        write.write_all(&[1,2,3,4,5,6]).unwrap();
+        let mut buf: [u8; 4] = [0; 4];
+        assert_eq!(read.read(&mut buf).unwrap(), 4);
+        assert_eq!(buf, [1,2,3,4]);
+        let mut rest: [u8; 4] = [0; 4];
+        assert_eq!(read.read(&mut rest).unwrap(), 2);
+        assert_eq!(&rest[..2], &[5,6]);
+    }
+
+    #[test]
+    fn drain_to_vec_moves_everything() {
+        let (mut read, mut write) = new_io_channel();
+        write.write_all(&[1,2,3,4,5]).unwrap();
+        let drained = read.drain_to_vec();
+        assert_eq!(drained, vec![1,2,3,4,5]);
+        // The buffer is now empty, so a follow-up drain yields nothing.
+        assert!(read.drain_to_vec().is_empty());
+    }
+
+    #[test]
+    fn write_all_from_pumps_reader() {
+        let (mut read, mut write) = new_io_channel();
+        let mut src: &[u8] = &[9,8,7,6];
+        write.write_all_from(&mut src).unwrap();
+        assert_eq!(read.drain_to_vec(), vec![9,8,7,6]);
+    }
+
+    #[test]
+    fn write_all_from_respects_limit() {
+        let (mut read, mut write) = new_bounded_io_channel(4);
+        let mut src: &[u8] = &[1,2,3,4,5,6];
+        // The pump only reads what fits and stops with WouldBlock once the buffer is full.
+        let res = write.write_all_from(&mut src);
+        if let Err(e) = res {
+            assert_eq!(e.kind(), std::io::ErrorKind::WouldBlock);
+        } else {
+            assert!(false);
+        }
+        assert_eq!(read.drain_to_vec(), vec![1,2,3,4]);
+        // The unread tail of the source is untouched and can be pumped once capacity re-opens.
+        write.write_all_from(&mut src).unwrap();
+        assert_eq!(read.drain_to_vec(), vec![5,6]);
+    }
+
+    #[test]
+    fn cloned_writers_interleave() {
+        let (mut read, mut first) = new_io_channel();
+        let mut second = first.clone();
+        // Bytes land in the buffer in call order regardless of which handle wrote them.
+        first.write_all(&[1]).unwrap();
+        second.write_all(&[2]).unwrap();
+        first.write_all(&[3]).unwrap();
+        second.write_all(&[4]).unwrap();
+        let mut buf: [u8; 4] = [0; 4];
+        assert_eq!(read.read(&mut buf).unwrap(), 4);
+        assert_eq!(buf, [1,2,3,4]);
+    }
+
+    #[test]
+    fn all_writers_dropped_is_eof() {
+        let (mut read, first) = new_io_channel();
+        let mut second = first.clone();
+        second.write_all(&[1,2,3]).unwrap();
+        drop(first);
+        // One writer is gone but another survives, so this is not yet EOF.
+        assert!(!read.is_closed());
+        drop(second);
+        // Now every writer is gone: drain the remaining bytes, then observe EOF.
+        assert!(read.is_closed());
+        let mut buf: [u8; 8] = [0; 8];
+        assert_eq!(read.read(&mut buf).unwrap(), 3);
+        assert_eq!(read.read(&mut buf).unwrap(), 0);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn async_read_parks_until_write() {
+        use futures::executor::LocalPool;
+        use futures::task::LocalSpawnExt;
+        use futures::{AsyncReadExt, AsyncWriteExt};
+
+        let (mut read, mut write) = new_io_channel();
+        let mut pool = LocalPool::new();
+        let spawner = pool.spawner();
+
+        // Reader starts first and parks on an empty buffer.
+        let reader = spawner
+            .spawn_local_with_handle(async move {
+                let mut buf = [0u8; 5];
+                AsyncReadExt::read_exact(&mut read, &mut buf).await.unwrap();
+                buf
+            })
+            .unwrap();
+        // Run until the reader is parked, then feed it from the writer.
+        pool.run_until_stalled();
+        spawner
+            .spawn_local(async move {
+                // Disambiguate from the blocking `std::io::Write::write_all` also in scope.
+                AsyncWriteExt::write_all(&mut write, &[1, 2, 3, 4, 5]).await.unwrap();
+            })
+            .unwrap();
+        let got = pool.run_until(reader);
+        assert_eq!(got, [1, 2, 3, 4, 5]);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn async_write_backpressure_wakes_on_drain() {
+        use futures::executor::LocalPool;
+        use futures::task::LocalSpawnExt;
+        use futures::{AsyncReadExt, AsyncWriteExt};
+
+        let (mut read, mut write) = new_bounded_io_channel(4);
+        let mut pool = LocalPool::new();
+        let spawner = pool.spawner();
+
+        // The writer wants eight bytes through a four-byte channel, so it fills the buffer and then
+        // parks on the full queue.
+        spawner
+            .spawn_local(async move {
+                AsyncWriteExt::write_all(&mut write, &[1, 2, 3, 4, 5, 6, 7, 8]).await.unwrap();
+            })
+            .unwrap();
+        pool.run_until_stalled();
+
+        // Draining the reader must wake the parked writer so the transfer finishes rather than
+        // deadlocking.
+        let reader = spawner
+            .spawn_local_with_handle(async move {
+                let mut buf = [0u8; 8];
+                AsyncReadExt::read_exact(&mut read, &mut buf).await.unwrap();
+                buf
+            })
+            .unwrap();
+        let got = pool.run_until(reader);
+        assert_eq!(got, [1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn async_write_broken_pipe_on_reader_drop() {
+        use futures::executor::LocalPool;
+        use futures::task::LocalSpawnExt;
+        use futures::AsyncWriteExt;
+
+        let (read, mut write) = new_bounded_io_channel(4);
+        let mut pool = LocalPool::new();
+        let spawner = pool.spawner();
+
+        // Writer fills the four-byte buffer and then parks on the full queue.
+        let writer = spawner
+            .spawn_local_with_handle(async move {
+                AsyncWriteExt::write_all(&mut write, &[1, 2, 3, 4, 5, 6, 7, 8]).await
+            })
+            .unwrap();
+        pool.run_until_stalled();
+
+        // Dropping the reader must wake the parked writer so it resolves to BrokenPipe, not hang.
+        drop(read);
+        let result = pool.run_until(writer);
+        assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::BrokenPipe);
+    }
+
+    #[test]
+    fn write_half_detects_reader_drop() {
+        let (read, write) = new_io_channel();
+        assert!(!write.is_closed());
+        drop(read);
+        assert!(write.is_closed());
+    }
 }
\ No newline at end of file